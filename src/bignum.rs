@@ -0,0 +1,80 @@
+//! Arbitrary-precision counterparts of `steps::omega` and `fall::omega`.
+//!
+//! The fixed-width `steps::omega` returns `Option<u32>` and `fall::omega` can
+//! return `false` early, because their fast paths include fallible
+//! conversions, and `3n + 1` can overflow `T` there. On a `num::BigUint` or
+//! `num::BigInt` backend `3n + 1` never overflows and the functions here have
+//! no fallible step, so they return `u32`/`bool` directly.
+//!
+//! This is deliberately a standalone module rather than another `T` for the
+//! `Rules`/`Steps`/`Transformations` impls on `NonZero<T>`: those rely on
+//! `NonZero<T>`'s trailing-zeros fast path (`trailing_zeros`,
+//! `without_trailing_zeros`), which is `PrimInt`-only and bignums don't
+//! implement. The trailing-zeros removal here (`divide_while_even`) is
+//! reimplemented directly in terms of `num::Integer::div_floor` instead.
+
+use beetle_nonzero::NonZeroUnchecked;
+use num::{Integer, One};
+
+/// Returns `3n + 1`. Unlike `rules::odd_rule`, this can never overflow, since
+/// `T` here is expected to be a bignum (`num::BigUint` or `num::BigInt`).
+fn odd_rule<T: Integer>(n: T) -> T {
+    let three = T::one() + T::one() + T::one();
+    n * three + T::one()
+}
+
+/// Divides `n` by two for as long as it's even, returning the odd remainder
+/// alongside how many halvings (trailing zero bits) were removed.
+fn divide_while_even<T: Integer>(mut n: T) -> (T, u32) {
+    let two = T::one() + T::one();
+    let mut steps = 0;
+    while n.is_even() {
+        n = n.div_floor(&two);
+        steps += 1;
+    }
+    (n, steps)
+}
+
+/// Bignum counterpart of `steps::omega`. Always succeeds, so it returns a
+/// plain `u32` instead of `Option<u32>`.
+pub fn omega<T: Integer>(n: NonZeroUnchecked<T>) -> u32 {
+    if n.value.is_odd() {
+        omega_n_is_odd(n)
+    } else {
+        omega_n_is_even(n)
+    }
+}
+
+/// Bignum counterpart of `steps::omega_n_is_even`.
+pub fn omega_n_is_even<T: Integer>(n: NonZeroUnchecked<T>) -> u32 {
+    let (odd, steps_to_become_odd) = divide_while_even(n.value);
+    steps_to_become_odd + omega_n_is_odd(NonZeroUnchecked::new(odd))
+}
+
+/// Bignum counterpart of `steps::omega_n_is_odd`.
+pub fn omega_n_is_odd<T: Integer>(n: NonZeroUnchecked<T>) -> u32 {
+    let mut steps = 0;
+    let mut n = n.value;
+    while !n.is_one() {
+        let (rest, zeros) = divide_while_even(odd_rule(n));
+        n = rest;
+        steps += zeros + 1;
+    }
+    steps
+}
+
+/// Bignum counterpart of `fall::omega`. Has no fallible step, so it always
+/// returns `true` once `n` drops below `start` or reaches 1.
+/// Assumes you have already checked all numbers < `start`.
+pub fn omega_fall<T: Integer + Clone>(start: NonZeroUnchecked<T>) -> bool {
+    let start = start.value;
+    let mut n = start.clone();
+    while n >= start {
+        let (rest, _) = divide_while_even(n);
+        if rest.is_one() {
+            break;
+        }
+        n = odd_rule(rest);
+    }
+    true
+}
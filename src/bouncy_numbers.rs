@@ -2,6 +2,7 @@
 
 use beetle_nonzero::NonZeroUnchecked;
 
+use crate::range::NonZeroRange;
 use crate::Collatz;
 
 /// Finds a number N that takes the most steps S to reach 1 in a given range
@@ -11,12 +12,10 @@ pub fn alpha<T: Collatz>(start: NonZeroUnchecked<T>, end: NonZeroUnchecked<T>) -
     let mut record_number = T::zero();
     let mut record_steps = 0;
 
-    let (start, end) = (start.value, end.value);
-
-    for i in num::iter::range(start, end) {
-        let steps = crate::steps::omega(NonZeroUnchecked::new(i))?;
+    for n in NonZeroRange::new(start, end) {
+        let steps = crate::steps::omega(n)?;
         if record_steps < steps {
-            record_number = i;
+            record_number = n.value;
             record_steps = steps;
         }
     }
@@ -48,36 +47,34 @@ pub fn alpha<T: Collatz>(start: NonZeroUnchecked<T>, end: NonZeroUnchecked<T>) -
 //     )
 // }
 
-/// Finds a number N that takes the most steps S to reach 1 in a given range
-/// Returns (N, S)
+/// Same as `bouncy_numbers::alpha`, but scans the range in parallel with rayon.
+/// Returns (N, S), or `None` if `steps::omega` returns `None` for any number in the range.
 /// Note: the range provided must be ascending
-/// /// Same as `beetle_collatz::bouncy_numbers::optimized`, but is multi-threaded and probably way faster
-// #[cfg(feature = "threaded")]
-// pub fn omega_threaded<T: Collatz>(start: NonZero<T>, end: NonZero<T>) -> eyre::Result<(u128, u32)> {
-//     use rayon::prelude::{IntoParallelIterator, ParallelIterator};
-//     let (start, end) = (start.0, end.0);
-
-//     // preventing weirdness
-//     if start >= end {
-//         Err(eyre!(
-//             "bouncy_numbers::omega_threaded expects `start` to be less than `end`"
-//         ));
-//     }
+#[cfg(feature = "threaded")]
+pub fn omega_threaded<T: Collatz + Send + Sync>(
+    start: NonZeroUnchecked<T>,
+    end: NonZeroUnchecked<T>,
+) -> Option<(T, u32)> {
+    use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
-//     let ans = (start..end)
-//         .into_par_iter()
-//         .map(|n| (n, crate::steps::omega(NonZero(n)?)))
-//         .reduce(
-//             || (0_u128, 0_u32),
-//             |(a, a_steps), (b, b_steps)| -> (u128, u32) {
-//                 if a_steps > b_steps {
-//                     (a, a_steps)
-//                 } else {
-//                     (b, b_steps)
-//                 }
-//             },
-//         );
-// }
+    NonZeroRange::new(start, end)
+        .into_par_iter()
+        .map(|n| Some((n.value, crate::steps::omega(n)?)))
+        .reduce(
+            || Some((T::zero(), 0_u32)),
+            |a, b| {
+                let (num1, steps1) = a?;
+                let (num2, steps2) = b?;
+                // Ties break toward the smaller number, so the result is
+                // deterministic no matter how rayon schedules the split.
+                Some(if steps2 > steps1 || (steps2 == steps1 && num2 < num1) {
+                    (num2, steps2)
+                } else {
+                    (num1, steps1)
+                })
+            },
+        )
+}
 
 /// Finds every number N, which takes more steps to reach 1 than all numbers before it.
 /// Returns this as a sequence starting at START, and ending at END, with every number N paired with its corresponding number of steps S
@@ -85,14 +82,15 @@ pub fn calculate_bouncy_sequence<T: Collatz>(
     start: NonZeroUnchecked<T>,
     stop: NonZeroUnchecked<T>,
 ) -> Option<Vec<(T, u32)>> {
-    let mut retval = Vec::new();
+    let range = NonZeroRange::new(start, stop);
+    let mut retval = Vec::with_capacity(range.len());
     let mut record_steps = 0;
 
-    for n in num::iter::range(start.value, stop.value) {
-        let steps = crate::steps::omega(NonZeroUnchecked::new(n))?;
+    for n in range {
+        let steps = crate::steps::omega(n)?;
         if steps > record_steps {
             record_steps = steps;
-            retval.push((n, steps));
+            retval.push((n.value, steps));
         }
     }
     Some(retval)
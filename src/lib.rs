@@ -1,5 +1,8 @@
 //! A collection of functions relating to the Collatz conjecture
 
+pub mod bignum;
+pub mod range;
+pub mod reverse;
 pub mod traits;
 use std::ops::{Add, Shr};
 
@@ -9,6 +12,11 @@ pub use traits::*;
 
 impl<T> TwoThree for T where T: PrimInt + Integer + One + Add<Output = T> {}
 
+// These impls require `PrimInt` because `NonZero<T>`'s trailing-zeros fast path
+// (`trailing_zeros`, `without_trailing_zeros`) is fixed-width only. A `num::BigUint`
+// or `num::BigInt` can't satisfy this bound, so bignums can't go through `Rules`,
+// `Steps`, or `Transformations` here — use the standalone functions in `bignum`
+// instead, which reimplement the same trailing-zeros logic for arbitrary precision.
 impl<T> Rules for NonZero<T>
 where
     T: PrimInt + Integer + Shr<u32, Output = T>,
@@ -174,13 +182,19 @@ mod tests {
             .map(|n| unsafe { NonZero::new_unchecked(n).steps_to_one() })
             .collect();
         assert_eq!(steps, OEIS_STEPS.to_vec());
+    }
 
-        // BigUint
-        let start: u32 = 1;
-        let stop: u32 = 73;
+    // `NonZero<T>`'s trailing-zeros fast path relies on `T: PrimInt`, which
+    // `num::BigUint` doesn't implement, so BigUint support lives in
+    // `bignum::omega` instead of going through `Steps::steps_to_one`. This
+    // exercises that module directly against the same OEIS table.
+    #[test]
+    fn step_counts_for_biguint_range_are_correct() {
+        use beetle_nonzero::NonZeroUnchecked;
+        use num::BigUint;
 
-        let steps: Vec<u64> = (start..stop)
-            .map(|n| unsafe { NonZero::new_unchecked(n).steps_to_one() })
+        let steps: Vec<u64> = (1u32..73)
+            .map(|n| u64::from(crate::bignum::omega(NonZeroUnchecked::new(BigUint::from(n)))))
             .collect();
         assert_eq!(steps, OEIS_STEPS.to_vec());
     }
@@ -196,4 +210,100 @@ mod tests {
             .collect();
         assert_eq!(transforms, expected_transformations);
     }
+
+    #[test]
+    fn nonzero_range_reports_exact_length_and_reverses() {
+        use beetle_nonzero::NonZeroUnchecked;
+
+        use crate::range::NonZeroRange;
+
+        let start = NonZeroUnchecked::new(1u32);
+        let stop = NonZeroUnchecked::new(11u32);
+
+        let range = NonZeroRange::new(start, stop);
+        assert_eq!(range.len(), 10);
+
+        let forward: Vec<u32> = range.map(|n| n.value).collect();
+        assert_eq!(forward, (1..11).collect::<Vec<u32>>());
+
+        let backward: Vec<u32> = NonZeroRange::new(start, stop)
+            .rev()
+            .map(|n| n.value)
+            .collect();
+        assert_eq!(backward, (1..11).rev().collect::<Vec<u32>>());
+
+        let stepped: Vec<u32> = NonZeroRange::new(start, stop)
+            .step_by(2)
+            .map(|n| n.value)
+            .collect();
+        assert_eq!(stepped, (1..11).step_by(2).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn reverse_tree_finds_known_predecessors_within_depth() {
+        use crate::reverse::tree_from_one;
+
+        let nodes: Vec<(u32, u32)> = tree_from_one(5).collect();
+
+        // Even-rule predecessors of 1: 1 -> 2 -> 4 -> 8 -> 16.
+        assert!(nodes.contains(&(1, 0)));
+        assert!(nodes.contains(&(2, 1)));
+        assert!(nodes.contains(&(4, 2)));
+        assert!(nodes.contains(&(8, 3)));
+        assert!(nodes.contains(&(16, 4)));
+
+        // 16 is 4 (mod 6), so at depth 4 it also expands to the odd-rule
+        // predecessor (16 - 1) / 3 = 5, one level deeper, since 5 * 3 + 1 ==
+        // 16 -> 5 takes 5 forward steps to reach 1 (5 -> 16 -> 8 -> 4 -> 2 -> 1).
+        assert!(nodes.contains(&(5, 5)));
+
+        // 4 is also 4 (mod 6), but its quotient (4 - 1) / 3 = 1 is the
+        // trivial predecessor, so it must not reappear at any depth.
+        assert!(!nodes.iter().any(|&(n, depth)| n == 1 && depth != 0));
+
+        // Nothing should be expanded past the requested depth.
+        assert!(nodes.iter().all(|&(_, depth)| depth <= 5));
+    }
+
+    #[test]
+    #[cfg(feature = "unchecked")]
+    fn omega_unchecked_matches_checked_omega() {
+        use beetle_nonzero::NonZeroUnchecked;
+
+        for n in 1u32..73 {
+            let checked = crate::steps::omega(NonZeroUnchecked::new(n)).unwrap();
+            let unchecked = crate::steps::omega_unchecked(NonZeroUnchecked::new(n));
+            assert_eq!(checked, unchecked);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "threaded")]
+    fn threaded_bouncy_record_matches_sequential() {
+        use beetle_nonzero::NonZeroUnchecked;
+
+        let start = NonZeroUnchecked::new(1u32);
+        let stop = NonZeroUnchecked::new(1000u32);
+        assert_eq!(
+            crate::bouncy_numbers::alpha(start, stop),
+            crate::bouncy_numbers::omega_threaded(start, stop)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "threaded")]
+    fn threaded_check_range_matches_sequential() {
+        use beetle_nonzero::NonZeroUnchecked;
+
+        let start = NonZeroUnchecked::new(1u32);
+        let stop = NonZeroUnchecked::new(1000u32);
+        assert_eq!(
+            crate::check_range::omega(start, stop),
+            crate::check_range::omega_threaded(start, stop)
+        );
+        assert_eq!(
+            crate::check_range::omega_all_odds(start, stop),
+            crate::check_range::omega_all_odds_threaded(start, stop)
+        );
+    }
 }
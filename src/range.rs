@@ -0,0 +1,196 @@
+//! A `Step`-style replacement for driving ranges of `NonZero` integers.
+//!
+//! Every range function in this crate used to be built on `num::iter::range`,
+//! which only ever walks forward, recomputes its length lazily, and can't be
+//! split or reversed. [`NonZeroRange`] instead knows its length up front (via
+//! [`NonZeroStep::steps_between`]), so it implements `DoubleEndedIterator`
+//! (for `.rev()`) and `ExactSizeIterator` (for `.step_by(n)` with a real
+//! length, and for callers to preallocate `Vec`s before collecting).
+
+use beetle_nonzero::{NonZero, NonZeroUnchecked};
+use num::{Integer, NumCast, PrimInt};
+use std::ops::Shr;
+
+use crate::Collatz;
+
+/// Mirrors the redesigned `std::iter::Step` trait for the `NonZero` family.
+///
+/// Implementors describe how many successor steps separate two values, and
+/// how to move forward or backward by a given number of steps, which is all
+/// [`NonZeroRange`] needs to act as an `Iterator`, `DoubleEndedIterator`, and
+/// `ExactSizeIterator`.
+pub trait NonZeroStep: Copy {
+    /// Returns `Some(b - a)` if `a <= b`, or `None` if `b` is before `a` or
+    /// the distance between them overflows `usize`.
+    fn steps_between(a: &Self, b: &Self) -> Option<usize>;
+
+    /// Returns the value reached by taking `n` successor steps from `a`, or
+    /// `None` if that would overflow.
+    fn forward_checked(a: Self, n: usize) -> Option<Self>;
+
+    /// Returns the value reached by taking `n` predecessor steps from `a`, or
+    /// `None` if that would underflow past the smallest representable value.
+    fn backward_checked(a: Self, n: usize) -> Option<Self>;
+}
+
+impl<T: Collatz> NonZeroStep for NonZeroUnchecked<T> {
+    fn steps_between(a: &Self, b: &Self) -> Option<usize> {
+        if a.value > b.value {
+            return None;
+        }
+        NumCast::from(b.value - a.value)
+    }
+
+    fn forward_checked(a: Self, n: usize) -> Option<Self> {
+        let n: T = NumCast::from(n)?;
+        Some(Self::new(a.value.checked_add(&n)?))
+    }
+
+    fn backward_checked(a: Self, n: usize) -> Option<Self> {
+        let n: T = NumCast::from(n)?;
+        Some(Self::new(a.value.checked_sub(&n)?))
+    }
+}
+
+impl<T> NonZeroStep for NonZero<T>
+where
+    T: PrimInt + Integer + Shr<u32, Output = T>,
+{
+    fn steps_between(a: &Self, b: &Self) -> Option<usize> {
+        if a.get() > b.get() {
+            return None;
+        }
+        NumCast::from(b.get() - a.get())
+    }
+
+    fn forward_checked(a: Self, n: usize) -> Option<Self> {
+        let n: T = NumCast::from(n)?;
+        let value = a.get().checked_add(n)?;
+        Some(unsafe { Self::new_unchecked(value) })
+    }
+
+    fn backward_checked(a: Self, n: usize) -> Option<Self> {
+        let n: T = NumCast::from(n)?;
+        let value = a.get().checked_sub(n)?;
+        if value.is_zero() {
+            return None;
+        }
+        Some(unsafe { Self::new_unchecked(value) })
+    }
+}
+
+/// A half-open range `[start, end)` over a `NonZero`-like type.
+///
+/// Unlike `num::iter::Range`, this reports an exact length via
+/// `ExactSizeIterator::len` and can be driven from either end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonZeroRange<S> {
+    start: S,
+    end: S,
+}
+
+impl<S: NonZeroStep> NonZeroRange<S> {
+    /// Builds the half-open range `[start, end)`.
+    #[must_use]
+    pub fn new(start: S, end: S) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<S: NonZeroStep> Iterator for NonZeroRange<S> {
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if S::steps_between(&self.start, &self.end)? == 0 {
+            return None;
+        }
+        let current = self.start;
+        self.start = S::forward_checked(self.start, 1)?;
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = S::steps_between(&self.start, &self.end).unwrap_or(0);
+        (len, Some(len))
+    }
+}
+
+impl<S: NonZeroStep> DoubleEndedIterator for NonZeroRange<S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if S::steps_between(&self.start, &self.end)? == 0 {
+            return None;
+        }
+        self.end = S::backward_checked(self.end, 1)?;
+        Some(self.end)
+    }
+}
+
+impl<S: NonZeroStep> ExactSizeIterator for NonZeroRange<S> {
+    fn len(&self) -> usize {
+        S::steps_between(&self.start, &self.end).unwrap_or(0)
+    }
+}
+
+/// Lets rayon split a `NonZeroRange` in O(1) instead of walking it to find a
+/// midpoint, by handing the exact length straight to a `Producer`.
+#[cfg(feature = "threaded")]
+mod threaded {
+    use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+    use super::{NonZeroRange, NonZeroStep};
+
+    impl<S: NonZeroStep + Send> ParallelIterator for NonZeroRange<S> {
+        type Item = S;
+
+        fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where
+            C: UnindexedConsumer<Self::Item>,
+        {
+            bridge(self, consumer)
+        }
+
+        fn opt_len(&self) -> Option<usize> {
+            Some(IndexedParallelIterator::len(self))
+        }
+    }
+
+    impl<S: NonZeroStep + Send> IndexedParallelIterator for NonZeroRange<S> {
+        fn len(&self) -> usize {
+            ExactSizeIterator::len(self)
+        }
+
+        fn drive<C>(self, consumer: C) -> C::Result
+        where
+            C: Consumer<Self::Item>,
+        {
+            bridge(self, consumer)
+        }
+
+        fn with_producer<CB>(self, callback: CB) -> CB::Output
+        where
+            CB: ProducerCallback<Self::Item>,
+        {
+            callback.callback(NonZeroRangeProducer(self))
+        }
+    }
+
+    struct NonZeroRangeProducer<S>(NonZeroRange<S>);
+
+    impl<S: NonZeroStep + Send> Producer for NonZeroRangeProducer<S> {
+        type Item = S;
+        type IntoIter = NonZeroRange<S>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0
+        }
+
+        fn split_at(self, index: usize) -> (Self, Self) {
+            let mid = S::forward_checked(self.0.start, index)
+                .expect("split index must fall within the range's exact length");
+            let left = NonZeroRange { start: self.0.start, end: mid };
+            let right = NonZeroRange { start: mid, end: self.0.end };
+            (Self(left), Self(right))
+        }
+    }
+}
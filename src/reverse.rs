@@ -0,0 +1,68 @@
+//! Walks the Collatz tree backwards from 1, enumerating every number that
+//! reaches 1 in at most N forward steps.
+//!
+//! `Transformations::transformations_to_one` only walks forward, so finding
+//! "every number that reaches 1 within N steps" otherwise means brute-force
+//! scanning a whole range and checking each one. This instead expands 1's
+//! predecessors directly: a node `n` always has the even-rule predecessor
+//! `2n`, and also has the odd-rule predecessor `(n - 1) / 3` whenever `n` is
+//! `4 (mod 6)` (which makes that quotient odd) and the quotient is greater
+//! than `1` (excluding the trivial `1 -> 2 -> 4 -> 1` short cycle).
+
+use std::collections::VecDeque;
+
+use crate::Collatz;
+
+/// Lazily walks the reverse Collatz tree breadth-first, starting at `1`.
+///
+/// Yields `(n, depth)` pairs, where `depth` is the number of forward steps
+/// `n` takes to reach `1`. Expansion stops once `depth` reaches the
+/// requested maximum, so deeper levels are never computed.
+pub struct ReverseTree<T> {
+    queue: VecDeque<(T, u32)>,
+    max_depth: u32,
+}
+
+/// Starts a breadth-first walk of the reverse Collatz tree from `1`, down to
+/// (and including) `max_depth` forward steps.
+#[must_use]
+pub fn tree_from_one<T: Collatz>(max_depth: u32) -> ReverseTree<T> {
+    let mut queue = VecDeque::new();
+    queue.push_back((T::one(), 0));
+    ReverseTree { queue, max_depth }
+}
+
+/// Returns the odd-rule predecessor of `n`, i.e. `(n - 1) / 3`, if `n` is
+/// `4 (mod 6)` and that quotient is greater than `1`. Every node not
+/// matching this has no odd-rule predecessor at all.
+fn odd_predecessor<T: Collatz>(n: T) -> Option<T> {
+    let one = T::one();
+    let three = T::from_u8(3)?;
+    let six = T::from_u8(6)?;
+    if n % six != T::from_u8(4)? {
+        return None;
+    }
+    let quotient = (n - one) / three;
+    (quotient > one).then_some(quotient)
+}
+
+impl<T: Collatz> Iterator for ReverseTree<T> {
+    type Item = (T, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (n, depth) = self.queue.pop_front()?;
+
+        if depth < self.max_depth {
+            // Even-rule predecessor: n always came from 2n.
+            if let Some(double) = n.checked_add(&n) {
+                self.queue.push_back((double, depth + 1));
+            }
+            // Odd-rule predecessor, when one exists.
+            if let Some(odd) = odd_predecessor(n) {
+                self.queue.push_back((odd, depth + 1));
+            }
+        }
+
+        Some((n, depth))
+    }
+}
@@ -24,6 +24,23 @@ pub fn even_rule<T: Collatz>(n: NonZeroUnchecked<T>) -> NonZeroUnchecked<T> {
     NonZeroUnchecked::new(n.value / two)
 }
 
+/// Returns `3 * n + 1`, skipping the overflow checks that `rules::odd_rule` performs.
+/// A free function here, not a `Collatz` trait method, for the same reason `odd_rule`
+/// and `even_rule` above are: every low-level rule in this module is a free function
+/// over `NonZeroUnchecked<T>`, with `Collatz` only ever appearing as a bound.
+///
+/// # Safety precondition
+/// The caller must guarantee that `3 * n + 1` does not overflow `T` for every `n` this
+/// is called with, e.g. because the range being scanned has already been bounded to fit.
+/// Violating this panics when overflow checks are on (debug builds) and wraps silently
+/// otherwise, instead of returning `None`.
+#[cfg(feature = "unchecked")]
+pub fn odd_rule_unchecked<T: Collatz>(n: NonZeroUnchecked<T>) -> NonZeroUnchecked<T> {
+    let one = T::one();
+    let three = one + one + one;
+    NonZeroUnchecked::new(n.value * three + one)
+}
+
 /// Applies the rules of the collatz conjecture to a number N, and returns the result.
 /// If N is ODD: returns 3n + 1,
 /// If N is EVEN: returns n / 2.
@@ -55,3 +55,50 @@ pub fn omega_n_is_odd<T: Collatz>(n: NonZeroUnchecked<T>) -> Option<u32> {
     }
     Some(steps)
 }
+
+/// Same as `steps::omega`, but returns `u32` directly instead of `Option<u32>`.
+///
+/// `steps::omega_n_is_odd` already computes `3n + 1` with plain unchecked arithmetic
+/// (no `checked_mul`/`checked_add` branch to remove), so the only check this actually
+/// elides in the odd-step loop is the fallible `usize::try_from` on the shift-count
+/// conversion, replacing it with an infallible cast. For even `n` it also replaces the
+/// `Option` returned by `divide_while_even` with a plain shift. That's a real, if narrower, win than a `3n + 1` overflow
+/// check: one `?` less per odd step in the hottest loop in the crate.
+///
+/// # Safety precondition
+/// The caller must guarantee that the trailing-zero count of every `3n + 1` reached
+/// while walking this orbit fits in a `usize` shift amount (always true in practice
+/// for every `T` this crate supports; `try_from` only exists as a defensive check).
+#[cfg(feature = "unchecked")]
+pub fn omega_unchecked<T: Collatz>(n: NonZeroUnchecked<T>) -> u32 {
+    if n.value.is_odd() {
+        omega_n_is_odd_unchecked(n)
+    } else {
+        omega_n_is_even_unchecked(n)
+    }
+}
+
+/// Unchecked counterpart of `steps::omega_n_is_even`. See `omega_unchecked` for the safety precondition.
+#[cfg(feature = "unchecked")]
+pub fn omega_n_is_even_unchecked<T: Collatz>(n: NonZeroUnchecked<T>) -> u32 {
+    let steps = n.value.trailing_zeros();
+    let n = NonZeroUnchecked::new(n.value >> steps);
+    steps + omega_n_is_odd_unchecked(n)
+}
+
+/// Unchecked counterpart of `steps::omega_n_is_odd`. See `omega_unchecked` for the safety precondition.
+///
+/// The `3n + 1` step itself is delegated to `rules::odd_rule_unchecked` instead of
+/// being inlined here.
+#[cfg(feature = "unchecked")]
+pub fn omega_n_is_odd_unchecked<T: Collatz>(n: NonZeroUnchecked<T>) -> u32 {
+    let mut steps = 0;
+    let mut n = n.value;
+    while !n.is_one() {
+        let m = crate::rules::odd_rule_unchecked(NonZeroUnchecked::new(n)).value;
+        let zeros = m.trailing_zeros();
+        n = m >> zeros;
+        steps += zeros + 1;
+    }
+    steps
+}
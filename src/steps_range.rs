@@ -2,6 +2,7 @@
 
 use beetle_nonzero::NonZeroUnchecked;
 
+use crate::range::NonZeroRange;
 use crate::Collatz;
 
 /// Maps each number N in the range `nums` to its steps to reach 1 using steps::basic.
@@ -11,8 +12,7 @@ pub fn alpha<T: Collatz>(
     start: NonZeroUnchecked<T>,
     stop: NonZeroUnchecked<T>,
 ) -> impl Iterator<Item = u32> {
-    let [start, stop] = [start.value, stop.value];
-    num::iter::range(start, stop).map(|x| crate::steps::alpha(NonZeroUnchecked::new(x)))
+    NonZeroRange::new(start, stop).map(crate::steps::alpha)
 }
 
 /// Ideally much faster than steps_range::alpha, by use of steps::omega instea of steps::alpha.
@@ -22,6 +22,5 @@ pub fn omega<T: Collatz>(
     start: NonZeroUnchecked<T>,
     stop: NonZeroUnchecked<T>,
 ) -> impl Iterator<Item = Option<u32>> {
-    let (start, stop) = (start.value, stop.value);
-    num::iter::range(start, stop).map(|x| crate::steps::omega(NonZeroUnchecked::new(x)))
+    NonZeroRange::new(start, stop).map(crate::steps::omega)
 }